@@ -1,16 +1,113 @@
-use chrono::{DateTime, Duration, Local, TimeDelta, Timelike};
+use chrono::{DateTime, Datelike, Duration, Local, NaiveDate, TimeDelta, Timelike};
+use clap::{Parser, Subcommand};
 use colored::*;
 use directories::ProjectDirs;
+use notify_rust::Notification;
 use serde::{Deserialize, Serialize};
-use std::fs::{self, File};
-use std::io::{self, BufReader};
+use std::fs::{self, File, OpenOptions};
+use std::io::{self, BufReader, BufWriter, Write};
 use std::path::PathBuf;
+use std::thread;
+use std::time::Duration as StdDuration;
 use sysinfo::System;
 
+/// Ambient work-clock: tells you when you clocked in and when you're done.
+#[derive(Parser, Debug)]
+#[command(name = "workfetch", version, about)]
+struct Cli {
+    #[command(subcommand)]
+    command: Option<Command>,
+}
+
+#[derive(Subcommand, Debug)]
+enum Command {
+    /// Show the current work-day status (default when no subcommand is given)
+    Status {
+        /// Output mode: fancy (logo + colored table), plain, or json
+        #[arg(long, value_enum)]
+        output: Option<OutputMode>,
+        /// Skip the logo gutter, even in fancy mode
+        #[arg(long)]
+        no_logo: bool,
+    },
+    /// Edit work/break durations without hand-editing config.toml
+    Config {
+        /// Target work duration in minutes
+        #[arg(long)]
+        work: Option<u32>,
+        /// Planned break duration in minutes
+        #[arg(long = "break")]
+        break_minutes: Option<u32>,
+        /// Default output mode: fancy, plain, or json
+        #[arg(long, value_enum)]
+        output: Option<OutputMode>,
+        /// Shorthand for `--output plain`: persist running without the logo
+        #[arg(long)]
+        no_logo: bool,
+    },
+    /// Discard today's session and re-derive the start time from boot time
+    Reset,
+    /// Show rolling work-history statistics
+    Stat {
+        /// Number of trailing days to aggregate
+        #[arg(default_value_t = 14)]
+        days: i64,
+    },
+    /// Punch a break in or out
+    Break {
+        #[command(subcommand)]
+        action: BreakAction,
+    },
+    /// Stay resident and fire desktop notifications at break/end-of-day transitions
+    Watch,
+}
+
+#[derive(Subcommand, Debug)]
+enum BreakAction {
+    /// Open a new break segment
+    Start,
+    /// Close the currently open break segment
+    End,
+}
+
 // Structure to store in the file
 #[derive(Serialize, Deserialize, Debug)]
 struct WorkSession {
     start_time: DateTime<Local>,
+    #[serde(default)]
+    history_written: bool,
+    #[serde(default)]
+    segments: Vec<BreakSegment>,
+    #[serde(default)]
+    notified_milestones: Vec<Milestone>,
+}
+
+/// One punched break. `end` is `None` while the break is still open.
+#[derive(Serialize, Deserialize, Debug, Clone)]
+struct BreakSegment {
+    start: DateTime<Local>,
+    end: Option<DateTime<Local>>,
+}
+
+/// Sums a break timeline as of a given instant: closed segments count their
+/// full span, a segment still open at `as_of` counts the elapsed time since
+/// it started.
+fn total_break_time(segments: &[BreakSegment], as_of: DateTime<Local>) -> TimeDelta {
+    segments
+        .iter()
+        .map(|b| b.end.unwrap_or(as_of) - b.start)
+        .fold(TimeDelta::zero(), |acc, d| acc + d)
+}
+
+/// One finalized day, appended to `history.jsonl` once its target is reached
+/// or once a new day rolls over and the previous day's session goes stale.
+#[derive(Serialize, Deserialize, Debug)]
+struct HistoryRecord {
+    date: NaiveDate,
+    rounded_start: DateTime<Local>,
+    actual_end: DateTime<Local>,
+    worked_minutes: i64,
+    break_minutes: i64,
 }
 
 // User configurable working / break durations (in minutes)
@@ -18,12 +115,178 @@ struct WorkSession {
 struct UserConfig {
     work_minutes: u32,
     break_minutes: u32,
+    #[serde(default = "default_watch_interval_secs")]
+    watch_interval_secs: u32,
+    #[serde(default = "default_notifications_enabled")]
+    notifications_enabled: bool,
+    #[serde(default = "default_daily_slots")]
+    daily_slots: u32,
+    #[serde(default = "default_weekly_slots")]
+    weekly_slots: u32,
+    #[serde(default = "default_monthly_slots")]
+    monthly_slots: u32,
+    #[serde(default)]
+    output_mode: OutputMode,
+}
+
+/// How `status` renders its report.
+#[derive(clap::ValueEnum, Serialize, Deserialize, Debug, Clone, Copy, PartialEq, Eq, Default)]
+#[serde(rename_all = "lowercase")]
+enum OutputMode {
+    /// Logo + colored aligned table (default)
+    #[default]
+    Fancy,
+    /// No color, no logo — safe for piping
+    Plain,
+    /// Computed fields as a single JSON object, for scripting
+    Json,
+}
+
+fn default_daily_slots() -> u32 {
+    7
+}
+
+fn default_weekly_slots() -> u32 {
+    1
+}
+
+fn default_monthly_slots() -> u32 {
+    1
+}
+
+/// Where slotted session snapshots live, e.g. `sessions/daily/2026-07-29.json`.
+#[derive(Clone, Copy)]
+enum SlotKind {
+    Daily,
+    Weekly,
+    Monthly,
+}
+
+impl SlotKind {
+    fn dirname(self) -> &'static str {
+        match self {
+            SlotKind::Daily => "daily",
+            SlotKind::Weekly => "weekly",
+            SlotKind::Monthly => "monthly",
+        }
+    }
+}
+
+fn default_watch_interval_secs() -> u32 {
+    30
+}
+
+fn default_notifications_enabled() -> bool {
+    true
+}
+
+/// How long a Pomodoro-style work chunk runs before a break reminder fires.
+const WORK_CHUNK_MINUTES: i64 = 90;
+
+/// A one-shot notification transition, persisted on the session so `watch`
+/// never fires the same one twice in a day.
+#[derive(Serialize, Deserialize, Debug, Clone, Copy, PartialEq, Eq)]
+enum Milestone {
+    WorkChunkElapsed,
+    BreakExhausted,
+    EndOfDay,
 }
 
 fn main() {
-    // Load or create user configuration for work/break durations
-    let user_cfg: UserConfig = load_or_create_user_config();
+    let cli: Cli = Cli::parse();
 
+    match cli.command.unwrap_or(Command::Status {
+        output: None,
+        no_logo: false,
+    }) {
+        Command::Status { output, no_logo } => run_status(output, no_logo),
+        Command::Config {
+            work,
+            break_minutes,
+            output,
+            no_logo,
+        } => run_config(work, break_minutes, output, no_logo),
+        Command::Reset => run_reset(),
+        Command::Stat { days } => print_stat(days),
+        Command::Break { action } => match action {
+            BreakAction::Start => punch_break_start(),
+            BreakAction::End => punch_break_end(),
+        },
+        Command::Watch => run_watch(),
+    }
+}
+
+/// `workfetch config --work 480 --break 45 --output plain` — edits
+/// `UserConfig` in place without requiring the user to hand-edit the TOML
+/// file.
+fn run_config(
+    work: Option<u32>,
+    break_minutes: Option<u32>,
+    output: Option<OutputMode>,
+    no_logo: bool,
+) {
+    let mut cfg: UserConfig = load_or_create_user_config();
+
+    if let Some(work) = work {
+        cfg.work_minutes = work;
+    }
+    if let Some(break_minutes) = break_minutes {
+        cfg.break_minutes = break_minutes;
+    }
+    if let Some(output) = output {
+        cfg.output_mode = output;
+    } else if no_logo {
+        cfg.output_mode = OutputMode::Plain;
+    }
+
+    if let Ok(serialized) = toml::to_string(&cfg) {
+        let _ = fs::write(get_user_config_path(), serialized);
+    }
+
+    println!(
+        "{}",
+        format!(
+            "Updated config: work = {} min, break = {} min, output = {:?}",
+            cfg.work_minutes, cfg.break_minutes, cfg.output_mode
+        )
+        .green()
+    );
+}
+
+/// `workfetch reset` — discards today's `last_session.json` so the next
+/// status check re-derives the start time from boot time.
+fn run_reset() {
+    let _ = fs::remove_file(get_config_path());
+    // Also drop today's daily slot: `get_or_create_start_time` falls back to
+    // it when `last_session.json` is missing, which would otherwise recover
+    // the exact session we just asked to discard and make reset a no-op.
+    let _ = fs::remove_file(daily_slot_path(Local::now().date_naive()));
+    let start_time: DateTime<Local> = get_or_create_start_time();
+    println!(
+        "{}",
+        format!(
+            "Session reset. New start time: {}",
+            start_time.format("%H:%M:%S")
+        )
+        .green()
+    );
+}
+
+/// Everything derived from the current session and config for a single
+/// point in time — shared by the `status` display and `watch`'s
+/// notification checks so the two never drift apart.
+struct WorkStatus {
+    real_start_time: DateTime<Local>,
+    rounded_start_time: DateTime<Local>,
+    accumulated_break: TimeDelta,
+    open_break: Option<DateTime<Local>>,
+    end_time: DateTime<Local>,
+    remaining: TimeDelta,
+    source_label: &'static str,
+}
+
+/// Computes the current work-day status from persistent storage and config.
+fn compute_status(user_cfg: &UserConfig) -> WorkStatus {
     // Get or create start time from persistent storage
     let real_start_time: DateTime<Local> = get_or_create_start_time();
 
@@ -32,15 +295,44 @@ fn main() {
 
     // Calculate target time
     let work_duration: TimeDelta = Duration::minutes(user_cfg.work_minutes as i64);
-    let break_duration: TimeDelta = Duration::minutes(user_cfg.break_minutes as i64);
-    let total_required: TimeDelta = work_duration + break_duration;
+    let planned_break: TimeDelta = Duration::minutes(user_cfg.break_minutes as i64);
 
-    let end_time: DateTime<Local> = rounded_start_time + total_required;
+    // Sum the real break timeline instead of assuming the planned break was
+    // taken exactly: closed segments count their full span, an open segment
+    // counts the elapsed time since it started.
+    let now: DateTime<Local> = Local::now();
+    let session_segments: Vec<BreakSegment> = read_session(&get_config_path())
+        .map(|s| s.segments)
+        .unwrap_or_default();
+    let accumulated_break: TimeDelta = total_break_time(&session_segments, now);
+    let open_break: Option<DateTime<Local>> = session_segments
+        .iter()
+        .find(|b| b.end.is_none())
+        .map(|b| b.start);
+
+    // The target extends to cover break overruns but never shrinks below the
+    // planned minimum.
+    let end_time: DateTime<Local> =
+        rounded_start_time + work_duration + accumulated_break.max(planned_break);
 
     // Calculate remaining time
-    let now: DateTime<Local> = Local::now();
     let remaining: TimeDelta = end_time - now;
 
+    // Once the daily goal is reached, append today's record to the history
+    // log exactly once (the `history_written` flag on the session guards
+    // against re-appending on every subsequent invocation). The logged
+    // minutes are the real elapsed time, not the configured target, so a
+    // day that ran long or short shows up as such in `stat`.
+    if remaining.num_minutes() <= 0 {
+        let worked_minutes: i64 = (now - rounded_start_time - accumulated_break).num_minutes();
+        record_history_once(
+            rounded_start_time,
+            now,
+            worked_minutes,
+            accumulated_break.num_minutes(),
+        );
+    }
+
     // Visual indicator if we are using a restored time vs fresh boot
     let source_label: &str = if real_start_time.date_naive()
         == System::boot_time_as_datetime().date_naive()
@@ -51,43 +343,95 @@ fn main() {
         "System Start" // Using fresh boot time
     };
 
-    // Collect entries for side-by-side output
-    let mut entries: Vec<(&str, String, &str)> = Vec::new();
-    entries.push((
+    WorkStatus {
+        real_start_time,
+        rounded_start_time,
+        accumulated_break,
+        open_break,
+        end_time,
+        remaining,
         source_label,
-        real_start_time.format("%H:%M:%S").to_string(),
-        "blue",
-    ));
-    entries.push((
-        "Rounded Start",
-        rounded_start_time.format("%H:%M").to_string(),
-        "cyan",
-    ));
-    entries.push((
-        "---",
-        "-----------------------------------".to_string(),
-        "dimmed",
-    ));
-    entries.push((
-        "Target Work Time",
-        create_duration_string(user_cfg.work_minutes as i64),
-        "green",
-    ));
-    entries.push((
-        "Break Time",
-        create_duration_string(user_cfg.break_minutes as i64),
-        "green",
-    ));
-    entries.push((
-        "End of Day",
-        end_time.format("%H:%M").to_string(),
-        "magenta",
-    ));
-    entries.push((
-        "---",
-        "-----------------------------------".to_string(),
-        "dimmed",
-    ));
+    }
+}
+
+/// The computed fields scripts care about, emitted as-is in `json` mode.
+#[derive(Serialize)]
+struct StatusReport {
+    rounded_start: String,
+    end_of_day: String,
+    remaining_minutes: i64,
+    source_label: String,
+}
+
+/// `workfetch status` (also the default with no subcommand) — prints the
+/// current work-day snapshot.
+fn run_status(output_override: Option<OutputMode>, no_logo: bool) {
+    // Load or create user configuration for work/break durations
+    let user_cfg: UserConfig = load_or_create_user_config();
+    let mode: OutputMode = output_override.unwrap_or(user_cfg.output_mode);
+    let status: WorkStatus = compute_status(&user_cfg);
+    let WorkStatus {
+        real_start_time,
+        rounded_start_time,
+        accumulated_break,
+        open_break,
+        end_time,
+        remaining,
+        source_label,
+        ..
+    } = status;
+
+    // Collect entries for the rendered report
+    let mut entries: Vec<(&str, String, &str)> = vec![
+        (
+            source_label,
+            real_start_time.format("%H:%M:%S").to_string(),
+            "blue",
+        ),
+        (
+            "Rounded Start",
+            rounded_start_time.format("%H:%M").to_string(),
+            "cyan",
+        ),
+        (
+            "---",
+            "-----------------------------------".to_string(),
+            "dimmed",
+        ),
+        (
+            "Target Work Time",
+            create_duration_string(user_cfg.work_minutes as i64),
+            "green",
+        ),
+        (
+            "Break Time",
+            create_duration_string(user_cfg.break_minutes as i64),
+            "green",
+        ),
+        (
+            "Actual Break",
+            create_duration_string(accumulated_break.num_minutes()),
+            "green",
+        ),
+        (
+            "End of Day",
+            end_time.format("%H:%M").to_string(),
+            "magenta",
+        ),
+        (
+            "---",
+            "-----------------------------------".to_string(),
+            "dimmed",
+        ),
+    ];
+
+    if let Some(break_start) = open_break {
+        entries.push((
+            "On Break since",
+            break_start.format("%H:%M").to_string(),
+            "red",
+        ));
+    }
 
     if remaining.num_minutes() > 0 {
         entries.push((
@@ -104,7 +448,81 @@ fn main() {
         ));
     }
 
-    print_logo_and_entries(&entries);
+    let report = StatusReport {
+        rounded_start: rounded_start_time.format("%H:%M").to_string(),
+        end_of_day: end_time.format("%H:%M").to_string(),
+        remaining_minutes: remaining.num_minutes(),
+        source_label: source_label.to_string(),
+    };
+
+    render(&entries, &report, mode, !no_logo);
+}
+
+/// `workfetch watch` — stays resident, recomputing the status on an
+/// interval and firing a desktop notification the first time each
+/// milestone (work-chunk elapsed, break exhausted, end of day) is crossed.
+fn run_watch() {
+    println!("{}", "Watching... press Ctrl+C to stop.".dimmed());
+    loop {
+        let user_cfg: UserConfig = load_or_create_user_config();
+        let status: WorkStatus = compute_status(&user_cfg);
+
+        if user_cfg.notifications_enabled {
+            check_milestones(&status, &user_cfg);
+        }
+
+        thread::sleep(StdDuration::from_secs(user_cfg.watch_interval_secs as u64));
+    }
+}
+
+/// Fires any milestone whose condition is newly met, then records it on the
+/// session so it isn't fired again today.
+fn check_milestones(status: &WorkStatus, user_cfg: &UserConfig) {
+    let elapsed_work: TimeDelta =
+        Local::now() - status.rounded_start_time - status.accumulated_break;
+
+    let mut due: Vec<(Milestone, &str, &str)> = Vec::new();
+    if elapsed_work.num_minutes() >= WORK_CHUNK_MINUTES && status.open_break.is_none() {
+        due.push((
+            Milestone::WorkChunkElapsed,
+            "workfetch",
+            "You've been at it a while — time for a break.",
+        ));
+    }
+    let planned_break: TimeDelta = Duration::minutes(user_cfg.break_minutes as i64);
+    if status.open_break.is_some() && status.accumulated_break >= planned_break {
+        due.push((
+            Milestone::BreakExhausted,
+            "workfetch",
+            "Planned break is over — back to work.",
+        ));
+    }
+    if status.remaining.num_minutes() <= 0 {
+        due.push((
+            Milestone::EndOfDay,
+            "workfetch",
+            "End of Day reached 🎉",
+        ));
+    }
+
+    if due.is_empty() {
+        return;
+    }
+
+    let file_path: PathBuf = get_config_path();
+    let Ok(mut session) = read_session(&file_path) else {
+        return;
+    };
+
+    for (milestone, summary, body) in due {
+        if session.notified_milestones.contains(&milestone) {
+            continue;
+        }
+        let _ = Notification::new().summary(summary).body(body).show();
+        session.notified_milestones.push(milestone);
+    }
+
+    save_main_session(&session);
 }
 
 fn create_duration_string(total_minutes: i64) -> String {
@@ -131,6 +549,12 @@ fn load_or_create_user_config() -> UserConfig {
     let default_cfg = UserConfig {
         work_minutes: 480, // 8 hours
         break_minutes: 45, // 45 minutes
+        watch_interval_secs: default_watch_interval_secs(),
+        notifications_enabled: default_notifications_enabled(),
+        daily_slots: default_daily_slots(),
+        weekly_slots: default_weekly_slots(),
+        monthly_slots: default_monthly_slots(),
+        output_mode: OutputMode::default(),
     };
     if let Ok(serialized) = toml::to_string(&default_cfg) {
         let _ = fs::write(&path, serialized);
@@ -150,9 +574,58 @@ fn get_or_create_start_time() -> DateTime<Local> {
             // It is today's file. Return the stored time.
             return session.start_time;
         }
+
+        // The stored session is stale (it belongs to a previous day). Unless
+        // `record_history_once` already logged and flagged it (a status check
+        // ran after the goal was reached), log it now so the day
+        // isn't silently lost before we overwrite the file. We never saw the
+        // moment the user actually stopped, so the day's end is taken to be
+        // the midnight it rolled over at — but only when the gap is a single
+        // day: a tool left untouched for several days has no evidence of
+        // what happened on the days in between, so rather than fabricate a
+        // multi-day "shift" we just skip logging anything for the gap.
+        let stale_date: NaiveDate = session.start_time.date_naive();
+        if !session.history_written && (now.date_naive() - stale_date).num_days() == 1 {
+            let rounded_start: DateTime<Local> = round_to_nearest_15(session.start_time);
+            let rollover_time: DateTime<Local> = stale_date
+                .succ_opt()
+                .and_then(|d| d.and_hms_opt(0, 0, 0))
+                .and_then(|d| d.and_local_timezone(Local).single())
+                .unwrap_or(now);
+            let accumulated_break: TimeDelta = total_break_time(&session.segments, rollover_time);
+
+            // Cap elapsed time to a sane workday-plus-break span so a long
+            // idle stretch before the rollover can't inflate the record, and
+            // floor worked minutes at zero: `round_to_nearest_15` can round a
+            // start time in the last few minutes before midnight into the
+            // next day, making `rounded_start == rollover_time`.
+            let user_cfg: UserConfig = load_or_create_user_config();
+            let day_cap: TimeDelta =
+                Duration::minutes(user_cfg.work_minutes as i64 + user_cfg.break_minutes as i64);
+            let elapsed: TimeDelta = (rollover_time - rounded_start).min(day_cap);
+            let worked_minutes: i64 = (elapsed - accumulated_break).num_minutes().max(0);
+
+            append_history_record(
+                rounded_start,
+                rollover_time,
+                worked_minutes,
+                accumulated_break.num_minutes(),
+            );
+        }
     }
 
-    // If we are here, either no file exists OR the file is from an old date.
+    // Before giving up and re-deriving from boot time, check whether today
+    // already has a slotted snapshot (e.g. `last_session.json` was lost to a
+    // crash or clock change but today's daily slot survived it).
+    if let Ok(recovered) = read_session(&daily_slot_path(now.date_naive())) {
+        if recovered.start_time.date_naive() == now.date_naive() {
+            let start_time: DateTime<Local> = recovered.start_time;
+            save_main_session(&recovered);
+            return start_time;
+        }
+    }
+
+    // If we are here, neither a current file nor a same-day slot exists.
     // We must calculate a fresh start time based on current uptime.
     let boot_time_sec: u64 = System::boot_time();
     let boot_time: DateTime<Local> = DateTime::from_timestamp(boot_time_sec as i64, 0)
@@ -162,12 +635,178 @@ fn get_or_create_start_time() -> DateTime<Local> {
     // Save this new session to file
     let new_session: WorkSession = WorkSession {
         start_time: boot_time,
+        history_written: false,
+        segments: Vec::new(),
+        notified_milestones: Vec::new(),
     };
-    let _ = save_session(&file_path, &new_session); // Ignore write errors for CLI simplicity
+    save_main_session(&new_session);
 
     boot_time
 }
 
+/// Appends the current day's record to `history.jsonl` unless it has
+/// already been recorded, then flags the session so it isn't appended again.
+fn record_history_once(
+    rounded_start: DateTime<Local>,
+    actual_end: DateTime<Local>,
+    worked_minutes: i64,
+    break_minutes: i64,
+) {
+    let file_path: PathBuf = get_config_path();
+    let mut session = match read_session(&file_path) {
+        Ok(session) => session,
+        Err(_) => return,
+    };
+    if session.history_written {
+        return;
+    }
+
+    append_history_record(rounded_start, actual_end, worked_minutes, break_minutes);
+
+    session.history_written = true;
+    save_main_session(&session);
+}
+
+/// Appends one `HistoryRecord` line to `history.jsonl`.
+///
+/// Takes `worked_minutes`/`break_minutes` as already-computed elapsed time,
+/// not a `&UserConfig` — do not refactor this back to reading the values off
+/// `UserConfig`, that previously caused every record to echo the configured
+/// target instead of what was actually worked.
+fn append_history_record(
+    rounded_start: DateTime<Local>,
+    actual_end: DateTime<Local>,
+    worked_minutes: i64,
+    break_minutes: i64,
+) {
+    let record = HistoryRecord {
+        date: rounded_start.date_naive(),
+        rounded_start,
+        actual_end,
+        worked_minutes,
+        break_minutes,
+    };
+
+    let Ok(serialized) = serde_json::to_string(&record) else {
+        return;
+    };
+
+    if let Ok(file) = OpenOptions::new()
+        .create(true)
+        .append(true)
+        .open(get_history_path())
+    {
+        let mut writer = BufWriter::new(file);
+        let _ = writeln!(writer, "{}", serialized);
+    }
+}
+
+/// Reads every record from `history.jsonl`, skipping malformed lines.
+fn read_history() -> Vec<HistoryRecord> {
+    let Ok(contents) = fs::read_to_string(get_history_path()) else {
+        return Vec::new();
+    };
+    contents
+        .lines()
+        .filter_map(|line| serde_json::from_str(line).ok())
+        .collect()
+}
+
+/// Prints work-history statistics for the last `days` days (inclusive of
+/// today), e.g. average rounded start, total/average worked hours, how many
+/// days met the daily goal, and the longest/shortest day.
+fn print_stat(days: i64) {
+    let user_cfg: UserConfig = load_or_create_user_config();
+    let today: NaiveDate = Local::now().date_naive();
+    let window_start: NaiveDate = today - Duration::days(days - 1);
+
+    let records: Vec<HistoryRecord> = read_history()
+        .into_iter()
+        .filter(|r| r.date >= window_start && r.date <= today)
+        .collect();
+
+    println!();
+    if records.is_empty() {
+        println!("{}", format!("No history in the last {} days.", days).dimmed());
+        println!();
+        return;
+    }
+
+    let total_worked: i64 = records.iter().map(|r| r.worked_minutes).sum();
+    let avg_worked: i64 = total_worked / records.len() as i64;
+
+    let avg_start_secs: i64 = records
+        .iter()
+        .map(|r| r.rounded_start.num_seconds_from_midnight() as i64)
+        .sum::<i64>()
+        / records.len() as i64;
+    let avg_start: String = format!("{:02}:{:02}", avg_start_secs / 3600, (avg_start_secs % 3600) / 60);
+
+    let goals_met: usize = records
+        .iter()
+        .filter(|r| r.worked_minutes >= user_cfg.work_minutes as i64)
+        .count();
+
+    let longest = records.iter().max_by_key(|r| r.worked_minutes);
+    let shortest = records.iter().min_by_key(|r| r.worked_minutes);
+
+    println!("{}", format!("Stats for the last {} days", days).bold());
+    println!(
+        "{:<18} : {}",
+        "Days Tracked".bold(),
+        records.len().to_string().cyan().bold()
+    );
+    println!(
+        "{:<18} : {}",
+        "Avg. Start".bold(),
+        avg_start.cyan().bold()
+    );
+    println!(
+        "{:<18} : {}",
+        "Total Worked".bold(),
+        create_duration_string(total_worked).green().bold()
+    );
+    println!(
+        "{:<18} : {}",
+        "Avg. Worked/Day".bold(),
+        create_duration_string(avg_worked).green().bold()
+    );
+    println!(
+        "{:<18} : {}",
+        "Goal Met".bold(),
+        format!("{}/{}", goals_met, records.len()).yellow().bold()
+    );
+    if let Some(r) = longest {
+        println!(
+            "{:<18} : {} ({})",
+            "Longest Day".bold(),
+            create_duration_string(r.worked_minutes).magenta().bold(),
+            r.date
+        );
+    }
+    if let Some(r) = shortest {
+        println!(
+            "{:<18} : {} ({})",
+            "Shortest Day".bold(),
+            create_duration_string(r.worked_minutes).magenta().bold(),
+            r.date
+        );
+    }
+    println!();
+}
+
+/// Path to the append-only work-history log.
+fn get_history_path() -> PathBuf {
+    if let Some(proj_dirs) = ProjectDirs::from("com", "internal", "workfetch") {
+        let config_dir = proj_dirs.config_dir();
+        if !config_dir.exists() {
+            let _ = fs::create_dir_all(config_dir);
+        }
+        return config_dir.join("history.jsonl");
+    }
+    PathBuf::from("history.jsonl")
+}
+
 /// Helper to get a safe path to store the file: e.g., AppData/Roaming/WorkFetch
 fn get_config_path() -> PathBuf {
     if let Some(proj_dirs) = ProjectDirs::from("com", "internal", "workfetch") {
@@ -182,6 +821,78 @@ fn get_config_path() -> PathBuf {
     PathBuf::from("work_session.json")
 }
 
+/// Root directory for slotted daily/weekly/monthly session snapshots.
+fn get_sessions_dir() -> PathBuf {
+    if let Some(proj_dirs) = ProjectDirs::from("com", "internal", "workfetch") {
+        let config_dir = proj_dirs.config_dir();
+        let sessions_dir = config_dir.join("sessions");
+        if !sessions_dir.exists() {
+            let _ = fs::create_dir_all(&sessions_dir);
+        }
+        return sessions_dir;
+    }
+    PathBuf::from("sessions")
+}
+
+/// Directory holding one `SlotKind`'s snapshot files, created on demand.
+fn slot_dir(kind: SlotKind) -> PathBuf {
+    let dir = get_sessions_dir().join(kind.dirname());
+    if !dir.exists() {
+        let _ = fs::create_dir_all(&dir);
+    }
+    dir
+}
+
+/// Path to the daily slot for a given date, e.g. `sessions/daily/2026-07-29.json`.
+fn daily_slot_path(date: NaiveDate) -> PathBuf {
+    slot_dir(SlotKind::Daily).join(format!("{}.json", date))
+}
+
+/// Writes `session` into every slot (daily/weekly/monthly) for `now`'s date,
+/// then prunes each slot down to its configured retention count. Slot file
+/// names are zero-padded and lexically sortable, so the oldest files are
+/// always first after a plain sort.
+fn snapshot_session(session: &WorkSession, now: DateTime<Local>, user_cfg: &UserConfig) {
+    let today: NaiveDate = now.date_naive();
+
+    let _ = save_session(&daily_slot_path(today), session);
+    prune_slot(SlotKind::Daily, user_cfg.daily_slots);
+
+    let iso_week = today.iso_week();
+    let weekly_key: String = format!("{}-W{:02}", iso_week.year(), iso_week.week());
+    let _ = save_session(&slot_dir(SlotKind::Weekly).join(format!("{}.json", weekly_key)), session);
+    prune_slot(SlotKind::Weekly, user_cfg.weekly_slots);
+
+    let monthly_key: String = format!("{}-{:02}", today.year(), today.month());
+    let _ = save_session(&slot_dir(SlotKind::Monthly).join(format!("{}.json", monthly_key)), session);
+    prune_slot(SlotKind::Monthly, user_cfg.monthly_slots);
+}
+
+/// Removes the oldest files in a slot directory once it holds more than `keep`.
+fn prune_slot(kind: SlotKind, keep: u32) {
+    let Ok(entries) = fs::read_dir(slot_dir(kind)) else {
+        return;
+    };
+    let mut files: Vec<PathBuf> = entries.filter_map(|e| e.ok()).map(|e| e.path()).collect();
+    files.sort();
+
+    let keep: usize = keep as usize;
+    if files.len() > keep {
+        for stale in &files[..files.len() - keep] {
+            let _ = fs::remove_file(stale);
+        }
+    }
+}
+
+/// Writes the main `last_session.json` and mirrors it into the slotted
+/// snapshot store, so a crash or clock change never loses more than the
+/// time since the last command ran.
+fn save_main_session(session: &WorkSession) {
+    let _ = save_session(&get_config_path(), session);
+    let user_cfg: UserConfig = load_or_create_user_config();
+    snapshot_session(session, Local::now(), &user_cfg);
+}
+
 /// Path to user configuration file (TOML)
 fn get_user_config_path() -> PathBuf {
     if let Some(proj_dirs) = ProjectDirs::from("com", "internal", "workfetch") {
@@ -207,6 +918,48 @@ fn save_session(path: &PathBuf, session: &WorkSession) -> Result<(), io::Error>
     Ok(())
 }
 
+/// `workfetch break start` — opens a new break segment, unless one is
+/// already open.
+fn punch_break_start() {
+    // Ensure today's session exists before punching a break against it.
+    get_or_create_start_time();
+
+    let file_path: PathBuf = get_config_path();
+    let Ok(mut session) = read_session(&file_path) else {
+        return;
+    };
+
+    if session.segments.iter().any(|b| b.end.is_none()) {
+        println!("{}", "A break is already open.".yellow());
+        return;
+    }
+
+    session.segments.push(BreakSegment {
+        start: Local::now(),
+        end: None,
+    });
+    save_main_session(&session);
+    println!("{}", "Break started.".cyan());
+}
+
+/// `workfetch break end` — closes the currently open break segment, if any.
+fn punch_break_end() {
+    let file_path: PathBuf = get_config_path();
+    let Ok(mut session) = read_session(&file_path) else {
+        println!("{}", "No break is open.".yellow());
+        return;
+    };
+
+    match session.segments.iter_mut().find(|b| b.end.is_none()) {
+        Some(open) => {
+            open.end = Some(Local::now());
+            save_main_session(&session);
+            println!("{}", "Break ended.".cyan());
+        }
+        None => println!("{}", "No break is open.".yellow()),
+    }
+}
+
 // Helper extension to get boot time as DateTime easily
 trait BootTimeExt {
     fn boot_time_as_datetime() -> DateTime<Local>;
@@ -236,7 +989,64 @@ fn round_to_nearest_15(time: DateTime<Local>) -> DateTime<Local> {
     .unwrap()
 }
 
-fn print_logo_and_entries(entries: &[(&str, String, &str)]) {
+/// Renders a status report in the requested `OutputMode`. This is the one
+/// place that knows about color/logo/json — every caller just hands it
+/// entries and lets it decide how they reach the terminal.
+fn render(entries: &[(&str, String, &str)], report: &StatusReport, mode: OutputMode, show_logo: bool) {
+    if mode == OutputMode::Json {
+        if let Ok(json) = serde_json::to_string_pretty(report) {
+            println!("{}", json);
+        }
+        return;
+    }
+
+    let key_width: usize = entries
+        .iter()
+        .filter(|(k, _, _)| *k != "---" && !k.is_empty())
+        .map(|(k, _, _)| k.len())
+        .max()
+        .unwrap_or(0);
+
+    let colorize: bool = mode == OutputMode::Fancy;
+    let lines: Vec<String> = entries
+        .iter()
+        .map(|(k, v, color)| {
+            if *k == "---" {
+                if colorize {
+                    v.dimmed().to_string()
+                } else {
+                    v.clone()
+                }
+            } else if k.is_empty() {
+                if colorize {
+                    v.bold().to_string()
+                } else {
+                    v.clone()
+                }
+            } else if colorize {
+                format!("{:<key_width$} : {}", k.bold(), v.color(*color).bold())
+            } else {
+                format!("{:<key_width$} : {}", k, v)
+            }
+        })
+        .collect();
+
+    if show_logo && colorize {
+        print_with_logo(&lines);
+    } else {
+        print_plain(&lines);
+    }
+}
+
+fn print_plain(lines: &[String]) {
+    println!();
+    for line in lines {
+        println!("{}", line);
+    }
+    println!();
+}
+
+fn print_with_logo(lines: &[String]) {
     // Minified Beckhoff "B" logo
     let logo_lines = [
         "##################",
@@ -256,22 +1066,7 @@ fn print_logo_and_entries(entries: &[(&str, String, &str)]) {
     ];
 
     let logo_width: usize = logo_lines.iter().map(|l| l.len()).max().unwrap_or(0);
-
-    // Prepare formatted entry lines (with colors for text, not logo)
-    let formatted: Vec<String> = entries
-        .iter()
-        .map(|(k, v, color)| {
-            if *k == "---" {
-                v.dimmed().to_string()
-            } else if k.is_empty() {
-                v.bold().to_string()
-            } else {
-                format!("{:<18} : {}", k.bold(), v.color(*color).bold())
-            }
-        })
-        .collect();
-
-    let max_rows: usize = logo_lines.len().max(formatted.len());
+    let max_rows: usize = logo_lines.len().max(lines.len());
 
     println!();
     for i in 0..max_rows {
@@ -280,11 +1075,7 @@ fn print_logo_and_entries(entries: &[(&str, String, &str)]) {
         } else {
             ""
         };
-        let entry_part = if i < formatted.len() {
-            &formatted[i]
-        } else {
-            ""
-        };
+        let entry_part = if i < lines.len() { &lines[i] } else { "" };
         println!(
             "{:<logo_width$}    {}",
             logo_part,